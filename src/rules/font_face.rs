@@ -6,6 +6,7 @@ use crate::printer::Printer;
 use crate::properties::custom::CustomProperty;
 use crate::properties::font::{FontFamily, FontStretch, FontStyle, FontWeight};
 use crate::traits::{Parse, ToCss};
+use crate::values::percentage::Percentage;
 use crate::values::size::Size2D;
 use crate::values::string::CowArcStr;
 use crate::values::url::Url;
@@ -46,10 +47,74 @@ pub enum FontFaceProperty<'i> {
   FontStretch(Size2D<FontStretch>),
   /// The `unicode-range` property.
   UnicodeRange(Vec<UnicodeRange>),
+  /// The `ascent-override` property.
+  AscentOverride(MetricsOverride),
+  /// The `descent-override` property.
+  DescentOverride(MetricsOverride),
+  /// The `line-gap-override` property.
+  LineGapOverride(MetricsOverride),
+  /// The `size-adjust` property.
+  SizeAdjust(Percentage),
+  /// The `font-feature-settings` property.
+  FontFeatureSettings(FontFeatureSettings<'i>),
+  /// The `font-variation-settings` property.
+  FontVariationSettings(FontVariationSettings<'i>),
   /// An unknown or unsupported property.
   Custom(CustomProperty<'i>),
 }
 
+/// A value for the font metrics override descriptors
+/// (`ascent-override`, `descent-override`, and `line-gap-override`)
+/// in an `@font-face` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum MetricsOverride {
+  /// Use the corresponding metric from the font itself.
+  Normal,
+  /// Override the metric with the given non-negative percentage.
+  Percentage(Percentage),
+}
+
+impl<'i> Parse<'i> for MetricsOverride {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(MetricsOverride::Normal);
+    }
+
+    let percentage = parse_non_negative_percentage(input)?;
+    Ok(MetricsOverride::Percentage(percentage))
+  }
+}
+
+/// Parses a non-negative `<percentage>`, rejecting negative values as required
+/// by the font metrics override and `size-adjust` descriptor grammars.
+fn parse_non_negative_percentage<'i, 't>(
+  input: &mut Parser<'i, 't>,
+) -> Result<Percentage, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let percentage = Percentage::parse(input)?;
+  if percentage.0 < 0.0 {
+    return Err(location.new_custom_error(ParserError::InvalidValue));
+  }
+  Ok(percentage)
+}
+
+impl ToCss for MetricsOverride {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      MetricsOverride::Normal => dest.write_str("normal"),
+      MetricsOverride::Percentage(percentage) => percentage.to_css(dest),
+    }
+  }
+}
+
 /// A value for the [src](https://drafts.csswg.org/css-fonts/#src-desc)
 /// property in an `@font-face` rule.
 #[derive(Debug, Clone, PartialEq)]
@@ -352,6 +417,30 @@ pub struct UnicodeRange {
   pub end: u32,
 }
 
+/// Sorts a list of unicode ranges and merges adjacent or overlapping ranges,
+/// dropping fully contained duplicates. Two ranges are considered mergeable
+/// when the next range starts no more than one code point past the end of the
+/// current one. The result is the minimal equivalent set, in ascending order.
+fn merge_unicode_ranges(ranges: &[UnicodeRange]) -> Vec<UnicodeRange> {
+  let mut sorted = ranges.to_vec();
+  sorted.sort_by_key(|range| (range.start, range.end));
+
+  let mut merged: Vec<UnicodeRange> = Vec::with_capacity(sorted.len());
+  for range in sorted {
+    if let Some(last) = merged.last_mut() {
+      // Merge when the range overlaps or is adjacent to the previous one.
+      // `last.end + 1` cannot overflow since valid code points are <= 0x10FFFF.
+      if range.start <= last.end + 1 {
+        last.end = last.end.max(range.end);
+        continue;
+      }
+    }
+    merged.push(range);
+  }
+
+  merged
+}
+
 impl<'i> Parse<'i> for UnicodeRange {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let range = cssparser::UnicodeRange::parse(input)?;
@@ -415,6 +504,183 @@ impl ToCss for UnicodeRange {
   }
 }
 
+/// A value for the [font-feature-settings](https://drafts.csswg.org/css-fonts/#font-rend-desc)
+/// descriptor in an `@font-face` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum FontFeatureSettings<'i> {
+  /// No feature settings. Use the defaults defined by the font.
+  Normal,
+  /// A list of OpenType feature tags and their values.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Tags(Vec<FontFeature<'i>>),
+}
+
+/// A single OpenType feature tag and its value, as used in the
+/// `font-feature-settings` descriptor.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FontFeature<'i> {
+  /// The four-character OpenType feature tag.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub tag: CowArcStr<'i>,
+  /// The value applied to the feature. Defaults to `1` (i.e. `on`).
+  pub value: u32,
+}
+
+impl<'i> Parse<'i> for FontFeatureSettings<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontFeatureSettings::Normal);
+    }
+
+    let tags = input.parse_comma_separated(FontFeature::parse)?;
+    Ok(FontFeatureSettings::Tags(tags))
+  }
+}
+
+impl<'i> Parse<'i> for FontFeature<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let tag = input.expect_string()?.into();
+    let location = input.current_source_location();
+    let value = if let Ok(value) = input.try_parse(|input| input.expect_integer()) {
+      // The feature value grammar is a non-negative integer.
+      if value < 0 {
+        return Err(location.new_custom_error(ParserError::InvalidValue));
+      }
+      value as u32
+    } else if input.try_parse(|input| input.expect_ident_matching("on")).is_ok() {
+      1
+    } else if input.try_parse(|input| input.expect_ident_matching("off")).is_ok() {
+      0
+    } else {
+      1
+    };
+
+    Ok(FontFeature { tag, value })
+  }
+}
+
+impl<'i> ToCss for FontFeatureSettings<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      FontFeatureSettings::Normal => dest.write_str("normal"),
+      FontFeatureSettings::Tags(tags) => {
+        let len = tags.len();
+        for (idx, tag) in tags.iter().enumerate() {
+          tag.to_css(dest)?;
+          if idx < len - 1 {
+            dest.delim(',', false)?;
+          }
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+impl<'i> ToCss for FontFeature<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    serialize_string(&self.tag, dest)?;
+    // The value `1` is the default and can be dropped when minifying.
+    if self.value != 1 || !dest.minify {
+      dest.write_char(' ')?;
+      write!(dest, "{}", self.value)?;
+    }
+    Ok(())
+  }
+}
+
+/// A value for the [font-variation-settings](https://drafts.csswg.org/css-fonts/#font-rend-desc)
+/// descriptor in an `@font-face` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum FontVariationSettings<'i> {
+  /// No variation settings. Use the defaults defined by the font.
+  Normal,
+  /// A list of variation axis tags and their values.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Tags(Vec<FontVariation<'i>>),
+}
+
+/// A single variation axis tag and its value, as used in the
+/// `font-variation-settings` descriptor.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FontVariation<'i> {
+  /// The four-character variation axis tag.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub tag: CowArcStr<'i>,
+  /// The value applied to the axis.
+  pub value: f32,
+}
+
+impl<'i> Parse<'i> for FontVariationSettings<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontVariationSettings::Normal);
+    }
+
+    let tags = input.parse_comma_separated(FontVariation::parse)?;
+    Ok(FontVariationSettings::Tags(tags))
+  }
+}
+
+impl<'i> Parse<'i> for FontVariation<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let tag = input.expect_string()?.into();
+    let value = input.expect_number()?;
+    Ok(FontVariation { tag, value })
+  }
+}
+
+impl<'i> ToCss for FontVariationSettings<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      FontVariationSettings::Normal => dest.write_str("normal"),
+      FontVariationSettings::Tags(tags) => {
+        let len = tags.len();
+        for (idx, tag) in tags.iter().enumerate() {
+          tag.to_css(dest)?;
+          if idx < len - 1 {
+            dest.delim(',', false)?;
+          }
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+impl<'i> ToCss for FontVariation<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    serialize_string(&self.tag, dest)?;
+    dest.write_char(' ')?;
+    self.value.to_css(dest)?;
+    Ok(())
+  }
+}
+
 pub(crate) struct FontFaceDeclarationParser;
 
 /// Parse a declaration within {} block: `color: blue`
@@ -447,6 +713,16 @@ impl<'i> cssparser::DeclarationParser<'i> for FontFaceDeclarationParser {
       "font-style" => property!(FontStyle, FontStyle),
       "font-stretch" => property!(FontStretch, Size2D<FontStretch>),
       "unicode-range" => property!(UnicodeRange, Vec<UnicodeRange>),
+      "ascent-override" => property!(AscentOverride, MetricsOverride),
+      "descent-override" => property!(DescentOverride, MetricsOverride),
+      "line-gap-override" => property!(LineGapOverride, MetricsOverride),
+      "size-adjust" => {
+        if let Ok(c) = parse_non_negative_percentage(input) {
+          return Ok(FontFaceProperty::SizeAdjust(c))
+        }
+      },
+      "font-feature-settings" => property!(FontFeatureSettings, FontFeatureSettings),
+      "font-variation-settings" => property!(FontVariationSettings, FontVariationSettings),
       _ => {}
     }
 
@@ -522,7 +798,31 @@ impl<'i> ToCss for FontFaceProperty<'i> {
       FontStyle(value) => property!("font-style", value),
       FontWeight(value) => property!("font-weight", value),
       FontStretch(value) => property!("font-stretch", value),
-      UnicodeRange(value) => property!("unicode-range", value),
+      UnicodeRange(value) => {
+        dest.write_str("unicode-range")?;
+        dest.delim(':', false)?;
+        // When minifying, sort and merge the ranges into the minimal
+        // equivalent set before serializing each range.
+        let ranges = if dest.minify {
+          std::borrow::Cow::Owned(merge_unicode_ranges(value))
+        } else {
+          std::borrow::Cow::Borrowed(value.as_slice())
+        };
+        let len = ranges.len();
+        for (idx, range) in ranges.iter().enumerate() {
+          range.to_css(dest)?;
+          if idx < len - 1 {
+            dest.delim(',', false)?;
+          }
+        }
+        Ok(())
+      }
+      AscentOverride(value) => property!("ascent-override", value),
+      DescentOverride(value) => property!("descent-override", value),
+      LineGapOverride(value) => property!("line-gap-override", value),
+      SizeAdjust(value) => property!("size-adjust", value),
+      FontFeatureSettings(value) => property!("font-feature-settings", value),
+      FontVariationSettings(value) => property!("font-variation-settings", value),
       Custom(custom) => {
         dest.write_str(custom.name.as_ref())?;
         dest.delim(':', false)?;
@@ -531,3 +831,32 @@ impl<'i> ToCss for FontFaceProperty<'i> {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn range(start: u32, end: u32) -> UnicodeRange {
+    UnicodeRange { start, end }
+  }
+
+  #[test]
+  fn merges_adjacent_and_contained_ranges() {
+    // U+0-7F, U+40-5A, U+80 collapses to the single range U+0-80:
+    // U+40-5A is contained in U+0-7F, and U+80 is adjacent to it.
+    let merged = merge_unicode_ranges(&[range(0x0, 0x7F), range(0x40, 0x5A), range(0x80, 0x80)]);
+    assert_eq!(merged, vec![range(0x0, 0x80)]);
+  }
+
+  #[test]
+  fn sorts_and_keeps_disjoint_ranges() {
+    let merged = merge_unicode_ranges(&[range(0x100, 0x10F), range(0x0, 0xF)]);
+    assert_eq!(merged, vec![range(0x0, 0xF), range(0x100, 0x10F)]);
+  }
+
+  #[test]
+  fn merges_overlapping_ranges() {
+    let merged = merge_unicode_ranges(&[range(0x0, 0x30), range(0x20, 0x4F)]);
+    assert_eq!(merged, vec![range(0x0, 0x4F)]);
+  }
+}
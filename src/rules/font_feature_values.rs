@@ -0,0 +1,391 @@
+//! The `@font-feature-values` rule.
+
+use super::Location;
+use crate::error::{ParserError, PrinterError};
+use crate::printer::Printer;
+use crate::properties::font::FontFamily;
+use crate::traits::ToCss;
+use crate::values::ident::CustomIdent;
+use cssparser::*;
+use std::fmt::Write;
+
+/// A [@font-feature-values](https://drafts.csswg.org/css-fonts/#font-feature-values) rule.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FontFeatureValuesRule<'i> {
+  /// The font families the feature values apply to.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub family_names: Vec<FontFamily<'i>>,
+  /// The `@stylistic` feature values. Each names a single value.
+  pub stylistic: Vec<FontFeatureValuesDeclaration<'i>>,
+  /// The `@swash` feature values. Each names a single value.
+  pub swash: Vec<FontFeatureValuesDeclaration<'i>>,
+  /// The `@ornaments` feature values. Each names a single value.
+  pub ornaments: Vec<FontFeatureValuesDeclaration<'i>>,
+  /// The `@annotation` feature values. Each names a single value.
+  pub annotation: Vec<FontFeatureValuesDeclaration<'i>>,
+  /// The `@styleset` feature values. Each names one or more values.
+  pub styleset: Vec<FontFeatureValuesDeclaration<'i>>,
+  /// The `@character-variant` feature values. Each names one or two values.
+  pub character_variant: Vec<FontFeatureValuesDeclaration<'i>>,
+  /// The order in which the nested blocks first appeared in the source, used
+  /// to preserve the authored block grouping when serializing.
+  #[cfg_attr(feature = "serde", serde(skip))]
+  block_order: Vec<BlockName>,
+  /// The location of the rule in the source file.
+  pub loc: Location,
+}
+
+/// A single feature value declaration within one of the nested blocks of an
+/// `@font-feature-values` rule, mapping a custom name to one or more indices.
+///
+/// See [FontFeatureValuesRule](FontFeatureValuesRule).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FontFeatureValuesDeclaration<'i> {
+  /// The name the values are bound to.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: CustomIdent<'i>,
+  /// The feature indices.
+  pub values: Vec<u16>,
+}
+
+/// One of the nested blocks within an `@font-feature-values` rule.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BlockName {
+  Stylistic,
+  Swash,
+  Ornaments,
+  Annotation,
+  Styleset,
+  CharacterVariant,
+}
+
+/// How many values each declaration in a block accepts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BlockType {
+  /// Exactly one value (`@stylistic`, `@swash`, `@ornaments`, `@annotation`).
+  Single,
+  /// One or more values (`@styleset`).
+  Styleset,
+  /// One or two values (`@character-variant`).
+  CharacterVariant,
+}
+
+impl BlockName {
+  fn block_type(&self) -> BlockType {
+    match self {
+      BlockName::Styleset => BlockType::Styleset,
+      BlockName::CharacterVariant => BlockType::CharacterVariant,
+      _ => BlockType::Single,
+    }
+  }
+
+  fn at_rule_name(&self) -> &'static str {
+    match self {
+      BlockName::Stylistic => "stylistic",
+      BlockName::Swash => "swash",
+      BlockName::Ornaments => "ornaments",
+      BlockName::Annotation => "annotation",
+      BlockName::Styleset => "styleset",
+      BlockName::CharacterVariant => "character-variant",
+    }
+  }
+}
+
+/// Parses the declarations within one of the nested blocks of an
+/// `@font-feature-values` rule, e.g. `salt: 2`.
+struct FontFeatureValuesDeclarationParser {
+  block: BlockType,
+}
+
+/// Parses a non-negative `<integer>` feature index, rejecting negative and
+/// out-of-range values as required by the feature value block grammars.
+fn parse_non_negative_integer<'i, 't>(
+  input: &mut Parser<'i, 't>,
+) -> Result<u16, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let value = input.expect_integer()?;
+  if value < 0 || value > u16::MAX as i32 {
+    return Err(location.new_custom_error(ParserError::InvalidValue));
+  }
+  Ok(value as u16)
+}
+
+impl<'i> cssparser::DeclarationParser<'i> for FontFeatureValuesDeclarationParser {
+  type Declaration = FontFeatureValuesDeclaration<'i>;
+  type Error = ParserError<'i>;
+
+  fn parse_value<'t>(
+    &mut self,
+    name: CowRcStr<'i>,
+    input: &mut cssparser::Parser<'i, 't>,
+  ) -> Result<Self::Declaration, cssparser::ParseError<'i, Self::Error>> {
+    let mut values = Vec::new();
+    values.push(parse_non_negative_integer(input)?);
+
+    match self.block {
+      BlockType::Single => {}
+      BlockType::Styleset => {
+        while let Ok(value) = input.try_parse(parse_non_negative_integer) {
+          values.push(value);
+        }
+      }
+      BlockType::CharacterVariant => {
+        if let Ok(value) = input.try_parse(parse_non_negative_integer) {
+          values.push(value);
+        }
+      }
+    }
+
+    Ok(FontFeatureValuesDeclaration {
+      name: CustomIdent(name.into()),
+      values,
+    })
+  }
+}
+
+/// Default methods reject all at rules.
+impl<'i> AtRuleParser<'i> for FontFeatureValuesDeclarationParser {
+  type Prelude = ();
+  type AtRule = FontFeatureValuesDeclaration<'i>;
+  type Error = ParserError<'i>;
+}
+
+/// Default methods reject all qualified rules.
+impl<'i> QualifiedRuleParser<'i> for FontFeatureValuesDeclarationParser {
+  type Prelude = ();
+  type QualifiedRule = FontFeatureValuesDeclaration<'i>;
+  type Error = ParserError<'i>;
+}
+
+impl<'i> RuleBodyItemParser<'i, FontFeatureValuesDeclaration<'i>, ParserError<'i>>
+  for FontFeatureValuesDeclarationParser
+{
+  fn parse_declarations(&self) -> bool {
+    true
+  }
+
+  fn parse_qualified(&self) -> bool {
+    false
+  }
+}
+
+impl<'i> FontFeatureValuesRule<'i> {
+  pub(crate) fn parse<'t>(
+    family_names: Vec<FontFamily<'i>>,
+    input: &mut Parser<'i, 't>,
+    loc: Location,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut rule = FontFeatureValuesRule {
+      family_names,
+      stylistic: Vec::new(),
+      swash: Vec::new(),
+      ornaments: Vec::new(),
+      annotation: Vec::new(),
+      styleset: Vec::new(),
+      character_variant: Vec::new(),
+      block_order: Vec::new(),
+      loc,
+    };
+
+    let mut parser = RuleBodyParser::new(input, &mut rule);
+    while let Some(result) = parser.next() {
+      if let Err((err, _)) = result {
+        return Err(err);
+      }
+    }
+
+    Ok(rule)
+  }
+
+  fn block_for(&mut self, name: BlockName) -> &mut Vec<FontFeatureValuesDeclaration<'i>> {
+    match name {
+      BlockName::Stylistic => &mut self.stylistic,
+      BlockName::Swash => &mut self.swash,
+      BlockName::Ornaments => &mut self.ornaments,
+      BlockName::Annotation => &mut self.annotation,
+      BlockName::Styleset => &mut self.styleset,
+      BlockName::CharacterVariant => &mut self.character_variant,
+    }
+  }
+}
+
+impl<'i> AtRuleParser<'i> for FontFeatureValuesRule<'i> {
+  type Prelude = BlockName;
+  type AtRule = ();
+  type Error = ParserError<'i>;
+
+  fn parse_prelude<'t>(
+    &mut self,
+    name: CowRcStr<'i>,
+    input: &mut Parser<'i, 't>,
+  ) -> Result<Self::Prelude, ParseError<'i, Self::Error>> {
+    let block = match_ignore_ascii_case! { &name,
+      "stylistic" => BlockName::Stylistic,
+      "swash" => BlockName::Swash,
+      "ornaments" => BlockName::Ornaments,
+      "annotation" => BlockName::Annotation,
+      "styleset" => BlockName::Styleset,
+      "character-variant" => BlockName::CharacterVariant,
+      _ => return Err(input.new_error(BasicParseErrorKind::AtRuleInvalid(name)))
+    };
+    Ok(block)
+  }
+
+  fn parse_block<'t>(
+    &mut self,
+    prelude: Self::Prelude,
+    _: &ParserState,
+    input: &mut Parser<'i, 't>,
+  ) -> Result<Self::AtRule, ParseError<'i, Self::Error>> {
+    let mut declaration_parser = FontFeatureValuesDeclarationParser {
+      block: prelude.block_type(),
+    };
+    let mut declarations = Vec::new();
+    let mut parser = RuleBodyParser::new(input, &mut declaration_parser);
+    while let Some(result) = parser.next() {
+      match result {
+        Ok(decl) => declarations.push(decl),
+        Err((err, _)) => return Err(err),
+      }
+    }
+
+    if !self.block_order.contains(&prelude) {
+      self.block_order.push(prelude);
+    }
+    self.block_for(prelude).extend(declarations);
+    Ok(())
+  }
+}
+
+/// Default methods reject all qualified rules.
+impl<'i> QualifiedRuleParser<'i> for FontFeatureValuesRule<'i> {
+  type Prelude = ();
+  type QualifiedRule = ();
+  type Error = ParserError<'i>;
+}
+
+impl<'i> DeclarationParser<'i> for FontFeatureValuesRule<'i> {
+  type Declaration = ();
+  type Error = ParserError<'i>;
+}
+
+impl<'i> RuleBodyItemParser<'i, (), ParserError<'i>> for FontFeatureValuesRule<'i> {
+  fn parse_declarations(&self) -> bool {
+    false
+  }
+
+  fn parse_qualified(&self) -> bool {
+    false
+  }
+}
+
+impl<'i> ToCss for FontFeatureValuesRule<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.add_mapping(self.loc);
+    dest.write_str("@font-feature-values ")?;
+    let len = self.family_names.len();
+    for (idx, name) in self.family_names.iter().enumerate() {
+      name.to_css(dest)?;
+      if idx < len - 1 {
+        dest.delim(',', false)?;
+      }
+    }
+
+    dest.whitespace()?;
+    dest.write_char('{')?;
+    dest.indent();
+
+    // Emit the blocks in the order they first appeared in the source to
+    // preserve the authored grouping. Rules constructed without source order
+    // (e.g. deserialized) fall back to a canonical order.
+    const CANONICAL_ORDER: [BlockName; 6] = [
+      BlockName::Stylistic,
+      BlockName::Styleset,
+      BlockName::CharacterVariant,
+      BlockName::Swash,
+      BlockName::Ornaments,
+      BlockName::Annotation,
+    ];
+    if self.block_order.is_empty() {
+      for block in CANONICAL_ORDER {
+        self.write_block(dest, block.at_rule_name(), self.block_for_ref(block))?;
+      }
+    } else {
+      for block in &self.block_order {
+        self.write_block(dest, block.at_rule_name(), self.block_for_ref(*block))?;
+      }
+    }
+
+    dest.dedent();
+    dest.newline()?;
+    dest.write_char('}')
+  }
+}
+
+impl<'i> FontFeatureValuesRule<'i> {
+  fn block_for_ref(&self, name: BlockName) -> &[FontFeatureValuesDeclaration<'i>] {
+    match name {
+      BlockName::Stylistic => &self.stylistic,
+      BlockName::Swash => &self.swash,
+      BlockName::Ornaments => &self.ornaments,
+      BlockName::Annotation => &self.annotation,
+      BlockName::Styleset => &self.styleset,
+      BlockName::CharacterVariant => &self.character_variant,
+    }
+  }
+
+  fn write_block<W>(
+    &self,
+    dest: &mut Printer<W>,
+    name: &str,
+    declarations: &[FontFeatureValuesDeclaration<'i>],
+  ) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if declarations.is_empty() {
+      return Ok(());
+    }
+
+    dest.newline()?;
+    dest.write_char('@')?;
+    dest.write_str(name)?;
+    dest.whitespace()?;
+    dest.write_char('{')?;
+    dest.indent();
+    let len = declarations.len();
+    for (i, decl) in declarations.iter().enumerate() {
+      dest.newline()?;
+      decl.to_css(dest)?;
+      if i != len - 1 || !dest.minify {
+        dest.write_char(';')?;
+      }
+    }
+    dest.dedent();
+    dest.newline()?;
+    dest.write_char('}')
+  }
+}
+
+impl<'i> ToCss for FontFeatureValuesDeclaration<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.name.to_css(dest)?;
+    dest.delim(':', false)?;
+    let len = self.values.len();
+    for (idx, value) in self.values.iter().enumerate() {
+      write!(dest, "{}", value)?;
+      if idx < len - 1 {
+        dest.write_char(' ')?;
+      }
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,249 @@
+//! The `@font-palette-values` rule.
+
+use super::Location;
+use crate::error::{ParserError, PrinterError};
+use crate::printer::Printer;
+use crate::properties::custom::CustomProperty;
+use crate::properties::font::FontFamily;
+use crate::traits::{Parse, ToCss};
+use crate::values::color::CssColor;
+use crate::values::ident::DashedIdent;
+use cssparser::*;
+use std::fmt::Write;
+
+/// A [@font-palette-values](https://drafts.csswg.org/css-fonts/#font-palette-values) rule.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FontPaletteValuesRule<'i> {
+  /// The name of the font palette.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: DashedIdent<'i>,
+  /// Declarations in the `@font-palette-values` rule.
+  pub properties: Vec<FontPaletteValuesProperty<'i>>,
+  /// The location of the rule in the source file.
+  pub loc: Location,
+}
+
+/// A property within an `@font-palette-values` rule.
+///
+/// See [FontPaletteValuesRule](FontPaletteValuesRule).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum FontPaletteValuesProperty<'i> {
+  /// The `font-family` property.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  FontFamily(Vec<FontFamily<'i>>),
+  /// The `base-palette` property.
+  BasePalette(BasePalette),
+  /// The `override-colors` property.
+  OverrideColors(Vec<OverrideColors>),
+  /// An unknown or unsupported property.
+  Custom(CustomProperty<'i>),
+}
+
+/// A value for the [base-palette](https://drafts.csswg.org/css-fonts/#base-palette-desc)
+/// descriptor in an `@font-palette-values` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum BasePalette {
+  /// The light base palette defined in the font.
+  Light,
+  /// The dark base palette defined in the font.
+  Dark,
+  /// The index of a base palette defined in the font.
+  Integer(u16),
+}
+
+impl<'i> Parse<'i> for BasePalette {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("light")).is_ok() {
+      return Ok(BasePalette::Light);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("dark")).is_ok() {
+      return Ok(BasePalette::Dark);
+    }
+
+    let location = input.current_source_location();
+    let index = input.expect_integer()?;
+    // The base palette index must be a non-negative integer.
+    if index < 0 || index > u16::MAX as i32 {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+    Ok(BasePalette::Integer(index as u16))
+  }
+}
+
+impl ToCss for BasePalette {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      BasePalette::Light => dest.write_str("light"),
+      BasePalette::Dark => dest.write_str("dark"),
+      BasePalette::Integer(index) => write!(dest, "{}", index).map_err(Into::into),
+    }
+  }
+}
+
+/// A value for the [override-colors](https://drafts.csswg.org/css-fonts/#override-color)
+/// descriptor in an `@font-palette-values` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OverrideColors {
+  /// The index of the palette entry to override.
+  pub index: u16,
+  /// The color to use for the palette entry.
+  pub color: CssColor,
+}
+
+impl<'i> Parse<'i> for OverrideColors {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let index = input.expect_integer()?;
+    // The palette entry index must be a non-negative integer.
+    if index < 0 || index > u16::MAX as i32 {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+    let color = CssColor::parse(input)?;
+    Ok(OverrideColors {
+      index: index as u16,
+      color,
+    })
+  }
+}
+
+impl ToCss for OverrideColors {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    write!(dest, "{}", self.index)?;
+    dest.write_char(' ')?;
+    self.color.to_css(dest)
+  }
+}
+
+pub(crate) struct FontPaletteValuesDeclarationParser;
+
+/// Parse a declaration within {} block: `base-palette: 0`
+impl<'i> cssparser::DeclarationParser<'i> for FontPaletteValuesDeclarationParser {
+  type Declaration = FontPaletteValuesProperty<'i>;
+  type Error = ParserError<'i>;
+
+  fn parse_value<'t>(
+    &mut self,
+    name: CowRcStr<'i>,
+    input: &mut cssparser::Parser<'i, 't>,
+  ) -> Result<Self::Declaration, cssparser::ParseError<'i, Self::Error>> {
+    let state = input.state();
+    match_ignore_ascii_case! { &name,
+      "font-family" => {
+        // Generic family names are not allowed here.
+        if let Ok(families) = input.parse_comma_separated(FontFamily::parse) {
+          if families.iter().all(|f| !matches!(f, FontFamily::Generic(_))) {
+            return Ok(FontPaletteValuesProperty::FontFamily(families))
+          }
+        }
+      },
+      "base-palette" => {
+        if let Ok(base_palette) = BasePalette::parse(input) {
+          return Ok(FontPaletteValuesProperty::BasePalette(base_palette))
+        }
+      },
+      "override-colors" => {
+        if let Ok(override_colors) = input.parse_comma_separated(OverrideColors::parse) {
+          return Ok(FontPaletteValuesProperty::OverrideColors(override_colors))
+        }
+      },
+      _ => {}
+    }
+
+    input.reset(&state);
+    return Ok(FontPaletteValuesProperty::Custom(CustomProperty::parse(
+      name.into(),
+      input,
+      &Default::default(),
+    )?));
+  }
+}
+
+/// Default methods reject all at rules.
+impl<'i> AtRuleParser<'i> for FontPaletteValuesDeclarationParser {
+  type Prelude = ();
+  type AtRule = FontPaletteValuesProperty<'i>;
+  type Error = ParserError<'i>;
+}
+
+impl<'i> ToCss for FontPaletteValuesRule<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.add_mapping(self.loc);
+    dest.write_str("@font-palette-values ")?;
+    self.name.to_css(dest)?;
+    dest.whitespace()?;
+    dest.write_char('{')?;
+    dest.indent();
+    let len = self.properties.len();
+    for (i, prop) in self.properties.iter().enumerate() {
+      dest.newline()?;
+      prop.to_css(dest)?;
+      if i != len - 1 || !dest.minify {
+        dest.write_char(';')?;
+      }
+    }
+    dest.dedent();
+    dest.newline()?;
+    dest.write_char('}')
+  }
+}
+
+impl<'i> ToCss for FontPaletteValuesProperty<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    use FontPaletteValuesProperty::*;
+    macro_rules! property {
+      ($prop: literal, $value: expr) => {{
+        dest.write_str($prop)?;
+        dest.delim(':', false)?;
+        $value.to_css(dest)
+      }};
+      ($prop: literal, $value: expr, $multi: expr) => {{
+        dest.write_str($prop)?;
+        dest.delim(':', false)?;
+        let len = $value.len();
+        for (idx, val) in $value.iter().enumerate() {
+          val.to_css(dest)?;
+          if idx < len - 1 {
+            dest.delim(',', false)?;
+          }
+        }
+        Ok(())
+      }};
+    }
+
+    match self {
+      FontFamily(value) => property!("font-family", value, true),
+      BasePalette(value) => property!("base-palette", value),
+      OverrideColors(value) => property!("override-colors", value, true),
+      Custom(custom) => {
+        dest.write_str(custom.name.as_ref())?;
+        dest.delim(':', false)?;
+        custom.value.to_css(dest, true)
+      }
+    }
+  }
+}